@@ -39,75 +39,380 @@
 //! ---
 //! 
 //! If you use the `custom_abort` feature, you can also use the `keep_going` one. This feature functions that, if your `custom_abort_error` macro works as a warning instead of a hard error, the code will keep going.
+//!
+//! ## Custom diagnostics
+//!
+//! Instead of the generic "Condition was met.", you can attach your own `message`, and optionally a `note` and a `help` line:
+//!
+//! ```rust, ignore
+//! use abort_if::abort_if;
+//! #[abort_if(feature = "x", message = "don't use foo with feature x", help = "call bar() instead")]
+//! fn foo() {
+//! 	using_that_feature();
+//! }
+//! ```
+//!
+//! ## Warnings instead of hard errors
+//!
+//! If a condition only deserves a warning, use `warn_if` instead. It keeps the function
+//! working and marks it `#[deprecated]` under the given condition, rather than aborting
+//! the build with `compile_error!`.
 
 use proc_macro::TokenStream;
-use proc_macro_error::proc_macro_error;
-use quote::quote;
+use proc_macro_error::{emit_error, proc_macro_error, set_dummy};
+use quote::{quote, quote_spanned};
+
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Brace,
+    AttributeArgs, Block, Expr, Ident, ItemFn, Lit, LitStr, Meta, NestedMeta, Stmt, Token,
+};
+
+/// The `runtime = expr` syntax accepted by [`abort_if`] as an alternative to a cfg
+/// predicate: `expr` is any boolean Rust expression, checked at call time instead of
+/// compile time.
+struct RuntimeArg {
+    expr: Expr,
+}
+
+impl Parse for RuntimeArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident != "runtime" {
+            return Err(syn::Error::new(ident.span(), "expected `runtime`"));
+        }
+        input.parse::<Token![=]>()?;
+        Ok(RuntimeArg {
+            expr: input.parse()?,
+        })
+    }
+}
 
-use syn::{parse_macro_input, parse_quote, parse_str, token::Brace, AttributeArgs, Block, ItemFn};
+/// One arm of a compound [`abort_if`] condition: a cfg predicate, optionally followed by
+/// `=> "message"` to give that specific predicate its own diagnostic.
+struct Arm {
+    predicate: NestedMeta,
+    message: Option<LitStr>,
+}
+
+impl Parse for Arm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let predicate: NestedMeta = input.parse()?;
+        let message = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Arm { predicate, message })
+    }
+}
+
+/// The full, comma-separated argument list of [`abort_if`]: a list of [`Arm`]s.
+struct Arms(Punctuated<Arm, Token![,]>);
+
+impl Parse for Arms {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Arms(Punctuated::parse_terminated(input)?))
+    }
+}
+
+/// The optional `message`, `note` and `help` keys that can be mixed in with the cfg
+/// predicate(s) passed to [`abort_if`] or [`warn_if`].
+struct Diagnostics {
+    message: Option<String>,
+    note: Option<String>,
+    help: Option<String>,
+}
+
+/// Separates the shared `message`/`note`/`help` keys from the actual cfg predicates, each
+/// with its own optional per-arm message (from `predicate => "message"`, only meaningful to
+/// [`abort_if`]'s compound arms; [`warn_if`] just passes `None` for every item).
+fn split_predicates(
+    args: impl IntoIterator<Item = (NestedMeta, Option<LitStr>)>,
+) -> (Vec<(NestedMeta, Option<String>)>, Diagnostics) {
+    let mut predicates = Vec::new();
+    let mut diagnostics = Diagnostics {
+        message: None,
+        note: None,
+        help: None,
+    };
+
+    for (predicate, message) in args {
+        if message.is_none() {
+            if let NestedMeta::Meta(Meta::NameValue(nv)) = &predicate {
+                if let Lit::Str(lit) = &nv.lit {
+                    if nv.path.is_ident("message") {
+                        diagnostics.message = Some(lit.value());
+                        continue;
+                    } else if nv.path.is_ident("note") {
+                        diagnostics.note = Some(lit.value());
+                        continue;
+                    } else if nv.path.is_ident("help") {
+                        diagnostics.help = Some(lit.value());
+                        continue;
+                    }
+                }
+            }
+        }
+        predicates.push((predicate, message.map(|lit| lit.value())));
+    }
+
+    (predicates, diagnostics)
+}
+
+/// Emits the "needs at least one cfg predicate" error shared by [`abort_if`] and [`warn_if`]
+/// for when their argument list turned out to contain only `message`/`note`/`help` keys and
+/// no actual cfg predicate.
+fn emit_missing_predicate_error(macro_name: &str, raw_args: TokenStream) {
+    let span = proc_macro2::TokenStream::from(raw_args)
+        .into_iter()
+        .next()
+        .map(|tt| tt.span())
+        .unwrap_or_else(proc_macro2::Span::call_site);
+    emit_error!(
+        span,
+        "`{}` needs at least one cfg predicate", macro_name;
+        help = "pass a predicate such as `feature = \"x\"` alongside `message`/`note`/`help`"
+    );
+}
+
+/// Appends `note`/`help`, when present, to a message already chosen for a diagnostic.
+fn attach_notes(mut message: String, diagnostics: &Diagnostics) -> String {
+    if let Some(note) = &diagnostics.note {
+        message.push_str(&format!("\n\nnote: {note}"));
+    }
+
+    if let Some(help) = &diagnostics.help {
+        message.push_str(&format!("\nhelp: {help}"));
+    }
+
+    message
+}
+
+/// Folds `message`/`note`/`help` into a single string, using `default_message` when no
+/// `message` was given. [`warn_if`] uses it as the note of its `#[deprecated]` attribute.
+fn render_diagnostic(diagnostics: &Diagnostics, default_message: &str) -> String {
+    let message = diagnostics
+        .message
+        .clone()
+        .unwrap_or_else(|| default_message.to_string());
+
+    attach_notes(message, diagnostics)
+}
 
 /// The main proc-macro. It takes arguments.
-/// 
+///
 /// ##### Example:
-/// 
+///
 /// ```rust, ignore
 /// #[abort_if(debug_assertions)]
 /// fn x() {
 /// 	// ...
 /// }
 /// ```
-/// 
+///
 /// This will fail if `debug_assertions` is enabled, so it will abort if it isn't on the release mode.
-/// 
+///
 /// The arguments can have nested conditionals, such as `not` or `any`, like this:
-/// 
+///
 /// ```rust, ignore
 /// #[abort_if(any(debug_assertions, feature = "debug_mode"))]
 /// fn x() {
 /// 	// ...
 /// }
 /// ```
-/// 
+///
 /// This code will abort if either `debug_assertions` is active, or the `debug_mode` feature is enabled.
+///
+/// You can also attach a `message`, a `note` and a `help` string to make the diagnostic
+/// actionable instead of the generic "Condition was met.":
+///
+/// ```rust, ignore
+/// #[abort_if(feature = "x", message = "don't use foo with feature x", help = "call bar() instead")]
+/// fn foo() {
+/// 	// ...
+/// }
+/// ```
+///
+/// Each top-level predicate in a compound condition can have its own message, with
+/// `predicate => "message"`. When several arms are active at once, every one of their
+/// messages is reported instead of a single generic error:
+///
+/// ```rust, ignore
+/// #[abort_if(feature = "x" => "x is unsupported here", debug_assertions => "remove before release")]
+/// fn foo() {
+/// 	// ...
+/// }
+/// ```
+///
+/// Conditions that can't be known at compile time can use `runtime = expr` instead of a
+/// cfg predicate, where `expr` is any boolean Rust expression:
+///
+/// ```rust, ignore
+/// #[abort_if(runtime = some_value > 100)]
+/// fn foo(some_value: u32) {
+/// 	// ...
+/// }
+/// ```
+///
+/// This checks `expr` at call time, panicking (with the caller's location) instead of
+/// failing the build.
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn abort_if(raw_args: TokenStream, input: TokenStream) -> TokenStream {
-    let raw_args_clone = raw_args.clone();
+    if let Ok(runtime_arg) = syn::parse::<RuntimeArg>(raw_args.clone()) {
+        return abort_if_runtime(runtime_arg.expr, input);
+    }
+
+    let raw_args_for_error = raw_args.clone();
     let mut input = parse_macro_input!(input as ItemFn);
-    let args = parse_macro_input!(raw_args_clone as AttributeArgs);
+    let arms = parse_macro_input!(raw_args as Arms);
+    let (predicates, diagnostics) =
+        split_predicates(arms.0.into_iter().map(|arm| (arm.predicate, arm.message)));
+
+    let (vis, sig) = (&input.vis, &input.sig);
+    set_dummy(quote! { #vis #sig { unimplemented!() } });
 
-    let throw_err_str: &str;
-    if cfg!(feature = "custom_abort") {
-        throw_err_str = "custom_abort_error!(\"Condition was met.\");"
-    } else {
-        throw_err_str = "compile_error!(\"Condition was met.\");"
+    if predicates.is_empty() {
+        emit_missing_predicate_error("abort_if", raw_args_for_error);
+        return TokenStream::from(quote! { #input });
+    }
+
+    // Every arm's `compile_error!`/`custom_abort_error!` lands in the *same* alternative
+    // function, each gated by its own `#[cfg(#predicate)]` statement attribute. That way
+    // only one `fn` variant of this name ever exists: if several arms are active at once,
+    // their statements all survive cfg-stripping and all of their messages are reported,
+    // instead of rustc seeing several `fn`s with the same name (E0428).
+    let mut predicate_metas = Vec::new();
+    let mut stmts = Vec::new();
+
+    for (predicate, arm_message) in predicates {
+        let message = arm_message.unwrap_or_else(|| {
+            diagnostics
+                .message
+                .clone()
+                .unwrap_or_else(|| "Condition was met.".to_string())
+        });
+        let diagnostic = attach_notes(message, &diagnostics);
+
+        // `quote_spanned!` puts the error at the predicate's own span (e.g. `feature = "x"`)
+        // rather than the call-site span `parse_quote!` would otherwise give it, so the
+        // diagnostic points at the offending arm of `#[abort_if(...)]` instead of the whole
+        // attribute.
+        let span = predicate.span();
+        let error_call = if cfg!(feature = "custom_abort") {
+            quote_spanned! {span=> custom_abort_error!(#diagnostic); }
+        } else {
+            quote_spanned! {span=> compile_error!(#diagnostic); }
+        };
+
+        let throw_err_stmt: Stmt = parse_quote! {
+            #[cfg(#predicate)]
+            #error_call
+        };
+
+        predicate_metas.push(predicate);
+        stmts.push(throw_err_stmt);
     }
 
     let mut alternative = ItemFn {
-        attrs: Vec::new(),
+        attrs: vec![parse_quote! {#[cfg(any(#(#predicate_metas),*))]}],
         vis: input.vis.clone(),
         sig: input.sig.clone(),
         block: Block {
             brace_token: Brace {
                 span: input.block.brace_token.span,
             },
-            // stmts: vec![parse_str(&format!("panic!(\"The condition `{}` was met, so the function `{}` panicked\");", raw_args.to_string(), input.sig.ident.to_string())).unwrap()]
-            stmts: vec![parse_str(throw_err_str).unwrap()],
+            stmts,
         }
         .into(),
     };
 
-	if cfg!(feature = "keep_going") {
-		alternative.block.stmts.append(&mut input.block.stmts);
-	}
-
-    for arg in args {
-        input.attrs.push(parse_quote! {#[cfg(not(#arg))]});
-        alternative.attrs.push(parse_quote! {#[cfg(#arg)]});
+    if cfg!(feature = "keep_going") {
+        alternative.block.stmts.extend(input.block.stmts.clone());
     }
 
+    input
+        .attrs
+        .push(parse_quote! { #[cfg(not(any(#(#predicate_metas),*)))] });
+
     TokenStream::from(quote! {
         #input
         #alternative
     })
 }
+
+/// Implements the `#[abort_if(runtime = expr)]` syntax: rather than generating two
+/// cfg-gated variants, it injects a guard as the first statement of the original body
+/// and makes the function `#[track_caller]`, so the panic reports the caller's location.
+fn abort_if_runtime(expr: Expr, input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as ItemFn);
+
+    let guard: Stmt = parse_quote! {
+        if #expr {
+            panic!("condition `{}` met in `{}`", stringify!(#expr), module_path!());
+        }
+    };
+    input.block.stmts.insert(0, guard);
+    input.attrs.push(parse_quote! { #[track_caller] });
+
+    TokenStream::from(quote! { #input })
+}
+
+/// A companion to [`abort_if`] for situations that deserve a warning rather than a hard
+/// build failure.
+///
+/// ##### Example:
+///
+/// ```rust, ignore
+/// #[warn_if(feature = "x", message = "this function is flagged under feature x")]
+/// fn foo() {
+/// 	// ...
+/// }
+/// ```
+///
+/// It reuses the same cfg-predicate (and `message`/`note`/`help`) parsing as `abort_if`,
+/// but keeps the original function body in the variant compiled when any predicate holds
+/// (`#[cfg(any(...))]`) instead of replacing it. `emit_warning!` can't be used here: it fires as soon as the macro is expanded, with
+/// no way to gate it on which predicate ends up active in the calling crate, so it would
+/// warn on every build regardless of whether the condition actually holds. Instead, the
+/// `#[cfg(any(...))]` variant is marked `#[deprecated(note = "...")]`, which is a real, stable
+/// warning that only appears when that variant survives cfg-stripping *and* is called.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn warn_if(raw_args: TokenStream, input: TokenStream) -> TokenStream {
+    let raw_args_for_error = raw_args.clone();
+    let mut input = parse_macro_input!(input as ItemFn);
+    let args = parse_macro_input!(raw_args as AttributeArgs);
+    let (predicates, diagnostics) = split_predicates(args.into_iter().map(|arg| (arg, None)));
+    let args: Vec<NestedMeta> = predicates.into_iter().map(|(predicate, _)| predicate).collect();
+
+    let (vis, sig) = (&input.vis, &input.sig);
+    set_dummy(quote! { #vis #sig { unimplemented!() } });
+
+    if args.is_empty() {
+        emit_missing_predicate_error("warn_if", raw_args_for_error);
+        return TokenStream::from(quote! { #input });
+    }
+
+    let diagnostic = render_diagnostic(&diagnostics, "This function is flagged under the given condition.");
+
+    let mut flagged = ItemFn {
+        attrs: vec![parse_quote! { #[deprecated(note = #diagnostic)] }],
+        vis: input.vis.clone(),
+        sig: input.sig.clone(),
+        block: input.block.clone(),
+    };
+
+    input.attrs.push(parse_quote! {#[cfg(not(any(#(#args),*)))]});
+    flagged.attrs.push(parse_quote! {#[cfg(any(#(#args),*))]});
+
+    TokenStream::from(quote! {
+        #input
+        #flagged
+    })
+}